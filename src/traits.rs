@@ -70,6 +70,10 @@ impl<T: Decode, H> MaybeHashed<T, H> {
         }
     }
 
+    /// Request the preimage of this call be kept available, if it is a `Hash`.
+    ///
+    /// Schedulers must call this when a task is first stored, so a call scheduled by hash can
+    /// be deposited fee-free ahead of dispatch.
     pub fn ensure_requested<P: PreimageProvider<H>>(&self) {
         match &self {
             Self::Value(_) => (),
@@ -77,6 +81,10 @@ impl<T: Decode, H> MaybeHashed<T, H> {
         }
     }
 
+    /// Release the preimage request placed by `ensure_requested`, if it is a `Hash`.
+    ///
+    /// Schedulers must call this exactly once when a task is finally removed from storage, so
+    /// that a requested preimage does not linger once nothing references it any more.
     pub fn ensure_unrequested<P: PreimageProvider<H>>(&self) {
         match &self {
             Self::Value(_) => (),
@@ -98,7 +106,87 @@ impl<T: Decode, H> MaybeHashed<T, H> {
     }
 }
 
+/// A point in time at which a call should be dispatched, expressed as a single absolute
+/// datetime or as a span relative to the current block, rather than as a full calendar
+/// `Schedule`.
+#[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub enum DispatchDateTime {
+    /// Dispatch once this absolute calendar datetime is reached.
+    At(DateTime),
+    /// Dispatch once this `Duration` has elapsed, measured from the datetime of the block in
+    /// which the task is scheduled.
+    After(Duration),
+}
+
+/// Error resolving a [`DispatchDateTime`] against the current block's datetime.
+#[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub enum DispatchTimeError {
+    /// The resolved datetime is not strictly in the future of the current block.
+    InThePast,
+}
+
+impl From<DispatchTimeError> for DispatchError {
+    fn from(err: DispatchTimeError) -> Self {
+        match err {
+            DispatchTimeError::InThePast => {
+                DispatchError::Other("DispatchDateTime resolved to a time in the past")
+            },
+        }
+    }
+}
+
+/// How a recurring task should catch up when one or more of its occurrences fall strictly
+/// between two blocks (e.g. because of a chain halt or an unusually slow block).
+#[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub enum MisfirePolicy {
+    /// Run a single catch-up dispatch for the missed occurrences, then advance to the next
+    /// future occurrence.
+    FireOnce,
+    /// Discard all missed occurrences and resume at the next future one.
+    Skip,
+    /// Dispatch once per missed occurrence, up to `max_catchup` dispatches in a single block.
+    FireAll {
+        /// Caps the number of catch-up dispatches performed for this task in one block, so
+        /// that a long outage cannot brick a block with unbounded work.
+        max_catchup: u32,
+    },
+}
+
+impl Default for MisfirePolicy {
+    fn default() -> Self {
+        MisfirePolicy::FireOnce
+    }
+}
+
+/// A recurring `Schedule` capped so it does not recur indefinitely.
+///
+/// Mirrors the `(period, count)` cap of the block-based scheduler, but expressed in calendar
+/// terms: a task stops once either bound is reached, whichever comes first.
+#[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct BoundedSchedule {
+    /// The underlying calendar recurrence.
+    pub schedule: Schedule,
+    /// Do not compute any occurrence beyond this datetime.
+    pub until: Option<DateTime>,
+    /// Stop after this many occurrences have been dispatched.
+    pub max_occurrences: Option<u32>,
+    /// How to catch up if one or more occurrences are missed between blocks.
+    pub misfire_policy: MisfirePolicy,
+}
+
+impl From<Schedule> for BoundedSchedule {
+    fn from(schedule: Schedule) -> Self {
+        BoundedSchedule {
+            schedule,
+            until: None,
+            max_occurrences: None,
+            misfire_policy: MisfirePolicy::default(),
+        }
+    }
+}
+
 /// A type that can be used as a scheduler.
+#[deprecated(note = "Use the `Bounded`-based `v3::Anon` instead")]
 pub trait Anon<BlockNumber, Call, Origin> {
     /// An address which can be used for removing a scheduled task.
     type Address: Codec + Clone + Eq + EncodeLike + Debug + TypeInfo;
@@ -108,6 +196,11 @@ pub trait Anon<BlockNumber, Call, Origin> {
     /// Schedule a dispatch to happen at the beginning of some block in the future.
     ///
     /// This is not named.
+    ///
+    /// Implementations must call `call.ensure_requested::<PreimageProvider>()` at the point the
+    /// task is stored, so that a `Hash` call has its preimage requested for fee-free deposit.
+    /// The matching `ensure_unrequested` must be called exactly once, when the task is finally
+    /// removed from storage: on `cancel`, or on the last dispatch of a periodic task.
     fn schedule(
         schedule: Schedule,
         priority: Priority,
@@ -115,6 +208,40 @@ pub trait Anon<BlockNumber, Call, Origin> {
         call: MaybeHashed<Call, Self::Hash>,
     ) -> Result<Self::Address, DispatchError>;
 
+    /// Schedule a recurring dispatch capped by an end date and/or a maximum number of
+    /// occurrences.
+    ///
+    /// On each dispatch, the remaining occurrence count is decremented and the next occurrence
+    /// is computed; the task is removed, and its preimage unrequested, once the count reaches
+    /// zero or the next occurrence would fall after `until`. If one or more occurrences were
+    /// missed between blocks, `schedule.misfire_policy` governs how the on-initialize hook
+    /// catches up. This is not named.
+    fn schedule_bounded(
+        schedule: BoundedSchedule,
+        priority: Priority,
+        origin: Origin,
+        call: MaybeHashed<Call, Self::Hash>,
+    ) -> Result<Self::Address, DispatchError>;
+
+    /// Return the number of occurrences remaining for a task scheduled via `schedule_bounded`,
+    /// or `None` if the task recurs without a cap (or was scheduled via `schedule`).
+    ///
+    /// Will return an error if the `address` is invalid.
+    fn remaining_occurrences(address: Self::Address) -> Result<Option<u32>, ()>;
+
+    /// Schedule a dispatch relative to the current block's wall-clock datetime, without the
+    /// caller having to compute an absolute `Schedule` themselves.
+    ///
+    /// `After(d)` is resolved against the datetime of the block in which this is called; if the
+    /// resolved datetime is not strictly in the future, the call returns
+    /// [`DispatchTimeError::InThePast`] converted into a `DispatchError`. This is not named.
+    fn schedule_after(
+        when: DispatchDateTime,
+        priority: Priority,
+        origin: Origin,
+        call: MaybeHashed<Call, Self::Hash>,
+    ) -> Result<Self::Address, DispatchError>;
+
     /// Cancel a scheduled task. If periodic, then it will cancel all further instances of that,
     /// also.
     ///
@@ -125,6 +252,9 @@ pub trait Anon<BlockNumber, Call, Origin> {
     ///
     /// NOTE2: This will not work to cancel periodic tasks after their initial execution. For
     /// that, you must name the task explicitly using the `Named` trait.
+    ///
+    /// Implementations must call `ensure_unrequested` on the cancelled task's call so that a
+    /// requested preimage is released exactly once.
     fn cancel(address: Self::Address) -> Result<(), ()>;
 
     /// Reschedule a task. For one-off tasks, this dispatch is guaranteed to succeed
@@ -142,9 +272,24 @@ pub trait Anon<BlockNumber, Call, Origin> {
     ///
     /// Will return an error if the `address` is invalid.
     fn next_dispatch_time(address: Self::Address) -> Result<BlockNumber, ()>;
+
+    /// Return the resolved datetime of the next dispatch for a given task, rather than the
+    /// block number it is expected to fall in.
+    ///
+    /// Will return an error if the `address` is invalid.
+    fn next_dispatch_datetime(address: Self::Address) -> Result<DateTime, ()>;
+
+    /// Return up to the next `n` absolute dispatch datetimes for a task, folding the task's
+    /// `Schedule` forward from the current block's datetime.
+    ///
+    /// Fewer than `n` datetimes are returned if the task does not recur that many more times
+    /// (e.g. a capped `BoundedSchedule` nearing its `until`/`max_occurrences` limit). Will
+    /// return an error if the `address` is invalid.
+    fn dispatch_times(address: Self::Address, n: u32) -> Result<Vec<DateTime>, ()>;
 }
 
 /// A type that can be used as a scheduler.
+#[deprecated(note = "Use the `Bounded`-based `v3::Named` instead")]
 pub trait Named<BlockNumber, Call, Origin> {
     /// An address which can be used for removing a scheduled task.
     type Address: Codec + Clone + Eq + EncodeLike + sp_std::fmt::Debug;
@@ -154,6 +299,11 @@ pub trait Named<BlockNumber, Call, Origin> {
     /// Schedule a dispatch to happen at the beginning of some block in the future.
     ///
     /// - `id`: The identity of the task. This must be unique and will return an error if not.
+    ///
+    /// Implementations must call `call.ensure_requested::<PreimageProvider>()` at the point the
+    /// task is stored, so that a `Hash` call has its preimage requested for fee-free deposit.
+    /// The matching `ensure_unrequested` must be called exactly once, when the task is finally
+    /// removed from storage: on `cancel_named`, or on the last dispatch of a periodic task.
     fn schedule_named(
         id: Vec<u8>,
         schedule: Schedule,
@@ -162,6 +312,47 @@ pub trait Named<BlockNumber, Call, Origin> {
         call: MaybeHashed<Call, Self::Hash>,
     ) -> Result<Self::Address, ()>;
 
+    /// Schedule a recurring, named dispatch capped by an end date and/or a maximum number of
+    /// occurrences.
+    ///
+    /// On each dispatch, the remaining occurrence count is decremented and the next occurrence
+    /// is computed; the task is removed, and its preimage unrequested, once the count reaches
+    /// zero or the next occurrence would fall after `until`. If one or more occurrences were
+    /// missed between blocks, `schedule.misfire_policy` governs how the on-initialize hook
+    /// catches up.
+    ///
+    /// - `id`: The identity of the task. This must be unique and will return an error if not.
+    fn schedule_named_bounded(
+        id: Vec<u8>,
+        schedule: BoundedSchedule,
+        priority: Priority,
+        origin: Origin,
+        call: MaybeHashed<Call, Self::Hash>,
+    ) -> Result<Self::Address, ()>;
+
+    /// Return the number of occurrences remaining for a task scheduled via
+    /// `schedule_named_bounded`, or `None` if the task recurs without a cap (or was scheduled
+    /// via `schedule_named`).
+    ///
+    /// Will return an error if the `id` is invalid.
+    fn remaining_occurrences(id: Vec<u8>) -> Result<Option<u32>, ()>;
+
+    /// Schedule a named dispatch relative to the current block's wall-clock datetime, without
+    /// the caller having to compute an absolute `Schedule` themselves.
+    ///
+    /// `After(d)` is resolved against the datetime of the block in which this is called; this
+    /// returns an error, matching `schedule_named`'s error convention, if the resolved datetime
+    /// is not strictly in the future (see [`DispatchTimeError::InThePast`]).
+    ///
+    /// - `id`: The identity of the task. This must be unique and will return an error if not.
+    fn schedule_named_after(
+        id: Vec<u8>,
+        when: DispatchDateTime,
+        priority: Priority,
+        origin: Origin,
+        call: MaybeHashed<Call, Self::Hash>,
+    ) -> Result<Self::Address, ()>;
+
     /// Cancel a scheduled, named task. If periodic, then it will cancel all further instances
     /// of that, also.
     ///
@@ -169,6 +360,9 @@ pub trait Named<BlockNumber, Call, Origin> {
     ///
     /// NOTE: This guaranteed to work only *before* the point that it is due to be executed.
     /// If it ends up being delayed beyond the point of execution, then it cannot be cancelled.
+    ///
+    /// Implementations must call `ensure_unrequested` on the cancelled task's call so that a
+    /// requested preimage is released exactly once.
     fn cancel_named(id: Vec<u8>) -> Result<(), ()>;
 
     /// Reschedule a task. For one-off tasks, this dispatch is guaranteed to succeed
@@ -182,7 +376,296 @@ pub trait Named<BlockNumber, Call, Origin> {
     ///
     /// Will return an error if the `id` is invalid.
     fn next_dispatch_time(id: Vec<u8>) -> Result<BlockNumber, ()>;
+
+    /// Return the resolved datetime of the next dispatch for a given task, rather than the
+    /// block number it is expected to fall in.
+    ///
+    /// Will return an error if the `id` is invalid.
+    fn next_dispatch_datetime(id: Vec<u8>) -> Result<DateTime, ()>;
+
+    /// Return up to the next `n` absolute dispatch datetimes for a task, folding the task's
+    /// `Schedule` forward from the current block's datetime.
+    ///
+    /// Fewer than `n` datetimes are returned if the task does not recur that many more times
+    /// (e.g. a capped `BoundedSchedule` nearing its `until`/`max_occurrences` limit). Will
+    /// return an error if the `id` is invalid.
+    fn dispatch_times(id: Vec<u8>, n: u32) -> Result<Vec<DateTime>, ()>;
 }
 
 use frame_support::traits::PreimageProvider;
 //use super::PreimageProvider;
+
+/// A length-and-hash-bounded alternative to [`MaybeHashed`], plus a `v3`-style scheduler
+/// contract built around it.
+///
+/// `Bounded<Call>` lets a pallet enforce `MaxEncodedLen` on calls stored inline, and account
+/// preimage deposits through the `QueryPreimage`/`StorePreimage` split rather than the single
+/// `PreimageProvider`. This mirrors the upstream FRAME scheduler's migration away from
+/// `MaybeHashed`.
+pub mod v3 {
+    use super::{
+        BoundedSchedule, Codec, Debug, Decode, DateTime, DispatchDateTime, DispatchError,
+        DispatchTimeError, Encode, EncodeLike, MaxEncodedLen, MaybeHashed, Priority, Result,
+        RuntimeDebug, Schedule, TypeInfo, Vec,
+    };
+
+    /// A means of looking up a preimage given its hash, without placing or releasing a request
+    /// for it to be kept available.
+    pub trait QueryPreimage<Hash> {
+        /// Returns the preimage for `hash`, if it is known.
+        fn get_preimage(hash: &Hash) -> Option<Vec<u8>>;
+    }
+
+    /// A means of requesting a preimage be kept available, and releasing that request, keyed
+    /// by the hash of its encoding.
+    pub trait StorePreimage<Hash>: QueryPreimage<Hash> {
+        /// Request that the preimage of `hash` be kept available.
+        fn request_preimage(hash: &Hash);
+        /// Release a previously placed request for the preimage of `hash`.
+        fn unrequest_preimage(hash: &Hash);
+    }
+
+    /// Either an inline, length-capped encoded call, or a hash-and-length handle to a call
+    /// whose preimage is resolved separately through [`QueryPreimage`].
+    #[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    pub enum Bounded<Call, Hash> {
+        /// The call, encoded inline, within the bound enforced by `MaxEncodedLen`.
+        Inline(Call),
+        /// A hash of the encoded call, together with its encoded length, resolved on dispatch
+        /// through `QueryPreimage`.
+        Lookup {
+            /// The hash of the encoded call.
+            hash: Hash,
+            /// The length of the encoded call, known without first fetching the preimage.
+            /// `QueryPreimage::get_preimage` takes only the hash, not this length.
+            len: u32,
+        },
+    }
+
+    impl<Call, Hash> From<Call> for Bounded<Call, Hash> {
+        fn from(call: Call) -> Self {
+            Bounded::Inline(call)
+        }
+    }
+
+    impl<Call, Hash> Bounded<Call, Hash> {
+        /// Convert a legacy [`MaybeHashed`] into a `Bounded`, given the real encoded length of
+        /// the call for the `Hash` case.
+        ///
+        /// There is deliberately no blanket `From<MaybeHashed<Call, Hash>>`: a bare
+        /// `MaybeHashed::Hash` carries no length of its own, and fabricating one would leave a
+        /// `Lookup` whose `len` does not match its preimage. Callers migrating away from
+        /// `MaybeHashed` must supply the real `len` (e.g. recovered from the legacy preimage
+        /// bookkeeping) through this instead.
+        pub fn from_legacy(call: MaybeHashed<Call, Hash>, len: u32) -> Self {
+            match call {
+                MaybeHashed::Value(c) => Bounded::Inline(c),
+                MaybeHashed::Hash(hash) => Bounded::Lookup { hash, len },
+            }
+        }
+
+        /// Request the preimage of this call be kept available, if it is a `Lookup`.
+        ///
+        /// Schedulers must call this when a task is first stored, so a `Lookup` call has its
+        /// preimage requested ahead of dispatch.
+        pub fn ensure_requested<P: StorePreimage<Hash>>(&self) {
+            if let Bounded::Lookup { hash, .. } = self {
+                P::request_preimage(hash);
+            }
+        }
+
+        /// Release the preimage request placed by `ensure_requested`, if it is a `Lookup`.
+        ///
+        /// Schedulers must call this exactly once when a task is finally removed from storage,
+        /// so that a requested preimage does not linger once nothing references it any more.
+        pub fn ensure_unrequested<P: StorePreimage<Hash>>(&self) {
+            if let Bounded::Lookup { hash, .. } = self {
+                P::unrequest_preimage(hash);
+            }
+        }
+    }
+
+    /// A type that can be used as a scheduler, storing calls as length-and-hash-bounded
+    /// [`Bounded`] handles rather than [`MaybeHashed`] ones.
+    pub trait Anon<BlockNumber, Call, Origin> {
+        /// An address which can be used for removing a scheduled task.
+        type Address: Codec + Clone + Eq + EncodeLike + Debug + TypeInfo;
+        /// A means of expressing a call by the hash of its encoded data.
+        type Hash;
+
+        /// Schedule a dispatch to happen at the beginning of some block in the future.
+        ///
+        /// This is not named.
+        ///
+        /// Implementations must call `call.ensure_requested::<StorePreimage>()` at the point
+        /// the task is stored, so that a `Lookup` call has its preimage requested ahead of
+        /// dispatch. The matching `ensure_unrequested` must be called exactly once, when the
+        /// task is finally removed from storage: on `cancel`, or on the last dispatch of a
+        /// periodic task.
+        fn schedule(
+            schedule: Schedule,
+            priority: Priority,
+            origin: Origin,
+            call: Bounded<Call, Self::Hash>,
+        ) -> Result<Self::Address, DispatchError>;
+
+        /// Schedule a recurring dispatch capped by an end date and/or a maximum number of
+        /// occurrences. This is not named.
+        fn schedule_bounded(
+            schedule: BoundedSchedule,
+            priority: Priority,
+            origin: Origin,
+            call: Bounded<Call, Self::Hash>,
+        ) -> Result<Self::Address, DispatchError>;
+
+        /// Return the number of occurrences remaining for a task scheduled via
+        /// `schedule_bounded`, or `None` if the task recurs without a cap (or was scheduled via
+        /// `schedule`).
+        ///
+        /// Will return an error if the `address` is invalid.
+        fn remaining_occurrences(address: Self::Address) -> Result<Option<u32>, ()>;
+
+        /// Schedule a dispatch relative to the current block's wall-clock datetime. This is not
+        /// named.
+        fn schedule_after(
+            when: DispatchDateTime,
+            priority: Priority,
+            origin: Origin,
+            call: Bounded<Call, Self::Hash>,
+        ) -> Result<Self::Address, DispatchError>;
+
+        /// Cancel a scheduled task. If periodic, then it will cancel all further instances of
+        /// that, also.
+        ///
+        /// Will return an error if the `address` is invalid.
+        ///
+        /// Implementations must call `ensure_unrequested` on the cancelled task's call so that
+        /// a requested preimage is released exactly once.
+        fn cancel(address: Self::Address) -> Result<(), ()>;
+
+        /// Reschedule a task. For one-off tasks, this dispatch is guaranteed to succeed only if
+        /// it is executed *before* the currently scheduled block.
+        fn reschedule(
+            address: Self::Address,
+            new_schedule: Schedule,
+        ) -> Result<Self::Address, DispatchError>;
+
+        /// Return the next dispatch time for a given task.
+        ///
+        /// Will return an error if the `address` is invalid.
+        fn next_dispatch_time(address: Self::Address) -> Result<BlockNumber, ()>;
+
+        /// Return the resolved datetime of the next dispatch for a given task, rather than the
+        /// block number it is expected to fall in.
+        ///
+        /// Will return an error if the `address` is invalid.
+        fn next_dispatch_datetime(address: Self::Address) -> Result<DateTime, ()>;
+
+        /// Return up to the next `n` absolute dispatch datetimes for a task, folding the
+        /// task's `Schedule` forward from the current block's datetime.
+        ///
+        /// Fewer than `n` datetimes are returned if the task does not recur that many more
+        /// times (e.g. a capped `BoundedSchedule` nearing its `until`/`max_occurrences` limit).
+        /// Will return an error if the `address` is invalid.
+        fn dispatch_times(address: Self::Address, n: u32) -> Result<Vec<DateTime>, ()>;
+    }
+
+    /// A type that can be used as a scheduler, storing calls as length-and-hash-bounded
+    /// [`Bounded`] handles rather than [`MaybeHashed`] ones.
+    pub trait Named<BlockNumber, Call, Origin> {
+        /// An address which can be used for removing a scheduled task.
+        type Address: Codec + Clone + Eq + EncodeLike + Debug;
+        /// A means of expressing a call by the hash of its encoded data.
+        type Hash;
+
+        /// Schedule a dispatch to happen at the beginning of some block in the future.
+        ///
+        /// - `id`: The identity of the task. This must be unique and will return an error if
+        /// not.
+        ///
+        /// Implementations must call `call.ensure_requested::<StorePreimage>()` at the point
+        /// the task is stored, so that a `Lookup` call has its preimage requested ahead of
+        /// dispatch. The matching `ensure_unrequested` must be called exactly once, when the
+        /// task is finally removed from storage: on `cancel_named`, or on the last dispatch of
+        /// a periodic task.
+        fn schedule_named(
+            id: Vec<u8>,
+            schedule: Schedule,
+            priority: Priority,
+            origin: Origin,
+            call: Bounded<Call, Self::Hash>,
+        ) -> Result<Self::Address, DispatchError>;
+
+        /// Schedule a recurring, named dispatch capped by an end date and/or a maximum number
+        /// of occurrences.
+        ///
+        /// - `id`: The identity of the task. This must be unique and will return an error if
+        /// not.
+        fn schedule_named_bounded(
+            id: Vec<u8>,
+            schedule: BoundedSchedule,
+            priority: Priority,
+            origin: Origin,
+            call: Bounded<Call, Self::Hash>,
+        ) -> Result<Self::Address, DispatchError>;
+
+        /// Return the number of occurrences remaining for a task scheduled via
+        /// `schedule_named_bounded`, or `None` if the task recurs without a cap (or was
+        /// scheduled via `schedule_named`).
+        ///
+        /// Will return an error if the `id` is invalid.
+        fn remaining_occurrences(id: Vec<u8>) -> Result<Option<u32>, ()>;
+
+        /// Schedule a named dispatch relative to the current block's wall-clock datetime,
+        /// without the caller having to compute an absolute `Schedule` themselves.
+        ///
+        /// `After(d)` is resolved against the datetime of the block in which this is called; if
+        /// the resolved datetime is not strictly in the future, the call returns
+        /// [`DispatchTimeError::InThePast`] converted into a `DispatchError`.
+        ///
+        /// - `id`: The identity of the task. This must be unique and will return an error if
+        /// not.
+        fn schedule_named_after(
+            id: Vec<u8>,
+            when: DispatchDateTime,
+            priority: Priority,
+            origin: Origin,
+            call: Bounded<Call, Self::Hash>,
+        ) -> Result<Self::Address, DispatchError>;
+
+        /// Cancel a scheduled, named task. If periodic, then it will cancel all further
+        /// instances of that, also.
+        ///
+        /// Will return an error if the `id` is invalid.
+        ///
+        /// Implementations must call `ensure_unrequested` on the cancelled task's call so that
+        /// a requested preimage is released exactly once.
+        fn cancel_named(id: Vec<u8>) -> Result<(), ()>;
+
+        /// Reschedule a task. For one-off tasks, this dispatch is guaranteed to succeed only if
+        /// it is executed *before* the currently scheduled block.
+        fn reschedule_named(
+            id: Vec<u8>,
+            new_schedule: Schedule,
+        ) -> Result<Self::Address, DispatchError>;
+
+        /// Return the next dispatch time for a given task.
+        ///
+        /// Will return an error if the `id` is invalid.
+        fn next_dispatch_time(id: Vec<u8>) -> Result<BlockNumber, ()>;
+
+        /// Return the resolved datetime of the next dispatch for a given task, rather than the
+        /// block number it is expected to fall in.
+        ///
+        /// Will return an error if the `id` is invalid.
+        fn next_dispatch_datetime(id: Vec<u8>) -> Result<DateTime, ()>;
+
+        /// Return up to the next `n` absolute dispatch datetimes for a task, folding the
+        /// task's `Schedule` forward from the current block's datetime.
+        ///
+        /// Fewer than `n` datetimes are returned if the task does not recur that many more
+        /// times (e.g. a capped `BoundedSchedule` nearing its `until`/`max_occurrences` limit).
+        /// Will return an error if the `id` is invalid.
+        fn dispatch_times(id: Vec<u8>, n: u32) -> Result<Vec<DateTime>, ()>;
+    }
+}